@@ -0,0 +1,103 @@
+use std::fmt;
+use std::io::{Cursor, Read};
+
+/// Represents one entry of the `BootstrapMethods` attribute of a class file.
+///
+/// Each entry associates a `CONSTANT_MethodHandle` constant pool reference,
+/// which identifies the bootstrap method itself, with the indexes of the
+/// static arguments that will be materialized and passed to it when linking
+/// an `invokedynamic` call site that refers to this entry.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct BootstrapMethod {
+    /// Index in the constant pool of the `CONSTANT_MethodHandle` describing
+    /// the bootstrap method to invoke.
+    pub method_ref: u16,
+
+    /// Indexes in the constant pool of the static arguments to pass to the
+    /// bootstrap method, in addition to the `Lookup`, name and `MethodType`
+    /// arguments the JVM provides implicitly.
+    pub arguments: Vec<u16>,
+}
+
+impl fmt::Display for BootstrapMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "method_ref: #{}, arguments: {:?}",
+            self.method_ref, self.arguments
+        )
+    }
+}
+
+/// Parses the `info` bytes of a class file's `BootstrapMethods` attribute
+/// into its entries, per the class file format:
+/// ```text
+/// u2 num_bootstrap_methods;
+/// {   u2 bootstrap_method_ref;
+///     u2 num_bootstrap_arguments;
+///     u2 bootstrap_arguments[num_bootstrap_arguments];
+/// } bootstrap_methods[num_bootstrap_methods];
+/// ```
+pub fn parse_bootstrap_methods_attribute(bytes: &[u8]) -> std::io::Result<Vec<BootstrapMethod>> {
+    let mut cursor = Cursor::new(bytes);
+    let num_bootstrap_methods = read_u16(&mut cursor)?;
+
+    let mut bootstrap_methods = Vec::with_capacity(num_bootstrap_methods as usize);
+    for _ in 0..num_bootstrap_methods {
+        let method_ref = read_u16(&mut cursor)?;
+        let num_bootstrap_arguments = read_u16(&mut cursor)?;
+        let mut arguments = Vec::with_capacity(num_bootstrap_arguments as usize);
+        for _ in 0..num_bootstrap_arguments {
+            arguments.push(read_u16(&mut cursor)?);
+        }
+        bootstrap_methods.push(BootstrapMethod {
+            method_ref,
+            arguments,
+        });
+    }
+    Ok(bootstrap_methods)
+}
+
+fn read_u16(cursor: &mut Cursor<&[u8]>) -> std::io::Result<u16> {
+    let mut buffer = [0u8; 2];
+    cursor.read_exact(&mut buffer)?;
+    Ok(u16::from_be_bytes(buffer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_empty_attribute() {
+        let bytes = [0x00, 0x00];
+        let bootstrap_methods = parse_bootstrap_methods_attribute(&bytes).unwrap();
+        assert!(bootstrap_methods.is_empty());
+    }
+
+    #[test]
+    fn parses_one_entry_with_two_arguments() {
+        #[rustfmt::skip]
+        let bytes = [
+            0x00, 0x01, // num_bootstrap_methods = 1
+            0x00, 0x2A, // bootstrap_method_ref = #42
+            0x00, 0x02, // num_bootstrap_arguments = 2
+            0x00, 0x10, // bootstrap_arguments[0] = #16
+            0x00, 0x11, // bootstrap_arguments[1] = #17
+        ];
+        let bootstrap_methods = parse_bootstrap_methods_attribute(&bytes).unwrap();
+        assert_eq!(
+            bootstrap_methods,
+            vec![BootstrapMethod {
+                method_ref: 42,
+                arguments: vec![16, 17],
+            }]
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_attribute() {
+        let bytes = [0x00, 0x01, 0x00, 0x2A];
+        assert!(parse_bootstrap_methods_attribute(&bytes).is_err());
+    }
+}
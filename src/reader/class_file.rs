@@ -1,8 +1,11 @@
 use std::fmt;
 
 use crate::reader::{
-    class_access_flags::ClassAccessFlags, class_file_field::ClassFileField,
-    class_file_method::ClassFileMethod, class_file_version::ClassFileVersion,
+    bootstrap_method::{parse_bootstrap_methods_attribute, BootstrapMethod},
+    class_access_flags::ClassAccessFlags,
+    class_file_field::ClassFileField,
+    class_file_method::ClassFileMethod,
+    class_file_version::ClassFileVersion,
     constant_pool::ConstantPool,
 };
 
@@ -17,6 +20,35 @@ pub struct ClassFile {
     pub interfaces: Vec<String>,
     pub fields: Vec<ClassFileField>,
     pub methods: Vec<ClassFileMethod>,
+
+    /// Entries of the `BootstrapMethods` attribute, indexed by the
+    /// `bootstrap_method_attr_index` found in `CONSTANT_InvokeDynamic`
+    /// constant pool entries. Filled only by `read_attribute` or
+    /// `set_bootstrap_methods_attribute`; empty until one of them is given
+    /// a `BootstrapMethods` attribute.
+    pub bootstrap_methods: Vec<BootstrapMethod>,
+}
+
+impl ClassFile {
+    /// Dispatches one entry of the class's top-level `attributes` table by
+    /// name: `BootstrapMethods` is parsed into `bootstrap_methods`, and any
+    /// other attribute name is ignored. The class file reader does not call
+    /// this, so a `ClassFile` it produces has an empty `bootstrap_methods`
+    /// even when the class has `invokedynamic` call sites.
+    pub fn read_attribute(&mut self, attribute_name: &str, info: &[u8]) -> std::io::Result<()> {
+        if attribute_name == "BootstrapMethods" {
+            self.set_bootstrap_methods_attribute(info)?;
+        }
+        Ok(())
+    }
+
+    /// Parses the `info` bytes of a class file's `BootstrapMethods`
+    /// attribute and stores the resulting entries on `self`. Prefer
+    /// `read_attribute`, which dispatches to this by name.
+    pub fn set_bootstrap_methods_attribute(&mut self, attribute_info: &[u8]) -> std::io::Result<()> {
+        self.bootstrap_methods = parse_bootstrap_methods_attribute(attribute_info)?;
+        Ok(())
+    }
 }
 
 impl fmt::Display for ClassFile {
@@ -37,6 +69,36 @@ impl fmt::Display for ClassFile {
         for method in self.methods.iter() {
             writeln!(f, "  - {}", method)?;
         }
+        writeln!(f, "bootstrap methods:")?;
+        for (index, bootstrap_method) in self.bootstrap_methods.iter().enumerate() {
+            writeln!(f, "  - #{}: {}", index, bootstrap_method)?;
+        }
         Ok(())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_attribute_dispatches_bootstrap_methods() {
+        #[rustfmt::skip]
+        let bytes = [
+            0x00, 0x01, // num_bootstrap_methods = 1
+            0x00, 0x2A, // bootstrap_method_ref = #42
+            0x00, 0x00, // num_bootstrap_arguments = 0
+        ];
+        let mut class_file = ClassFile::default();
+        class_file.read_attribute("BootstrapMethods", &bytes).unwrap();
+        assert_eq!(class_file.bootstrap_methods.len(), 1);
+        assert_eq!(class_file.bootstrap_methods[0].method_ref, 42);
+    }
+
+    #[test]
+    fn read_attribute_ignores_unknown_names() {
+        let mut class_file = ClassFile::default();
+        class_file.read_attribute("SomeFutureAttribute", &[0xFF]).unwrap();
+        assert!(class_file.bootstrap_methods.is_empty());
+    }
 }
\ No newline at end of file
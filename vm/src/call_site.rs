@@ -0,0 +1,10 @@
+use crate::class_and_method::ClassAndMethod;
+
+/// The result of linking an `invokedynamic` call site: the concrete target
+/// method that the site's bootstrap method resolved to. Once linked, a call
+/// site is cached by the owning [`crate::vm::Vm`] so that subsequent
+/// executions of the same site skip the bootstrap method entirely.
+#[derive(Debug, Clone)]
+pub struct CallSite<'a> {
+    pub target: ClassAndMethod<'a>,
+}
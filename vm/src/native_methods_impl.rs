@@ -0,0 +1,181 @@
+use crate::abstract_object::{AbstractObject, Array2};
+use crate::call_stack::CallStack;
+use crate::exceptions::MethodCallFailed;
+use crate::native_methods_registry::NativeMethodsRegistry;
+use crate::value::Value;
+use crate::vm::Vm;
+use crate::vm_error::VmError;
+
+/// Copies `length` elements of `src` starting at `src_pos` into `dest`
+/// starting at `dest_pos`. Backs `System.arraycopy` and `Vm::clone_array`.
+pub fn array_copy<'a>(
+    src: &AbstractObject<'a>,
+    src_pos: usize,
+    dest: &AbstractObject<'a>,
+    dest_pos: usize,
+    length: usize,
+) -> Result<(), VmError> {
+    for offset in 0..length {
+        let value = src.get_element(src_pos + offset);
+        dest.set_element(dest_pos + offset, value)?;
+    }
+    Ok(())
+}
+
+/// Registers the native methods this VM implements, which the class loader
+/// otherwise has no Java bytecode for.
+pub fn register_natives(registry: &mut NativeMethodsRegistry<'_>) {
+    register_boxing_natives(registry);
+    register_string_natives(registry);
+    register_reflection_natives(registry);
+    register_invoke_dynamic_bootstrap_natives(registry);
+}
+
+fn boxed_value_of<'a>(
+    vm: &mut Vm<'a>,
+    call_stack: &mut CallStack<'a>,
+    class_name: &str,
+    args: Vec<Value<'a>>,
+) -> Result<Option<Value<'a>>, MethodCallFailed<'a>> {
+    let value = match args.first() {
+        Some(Value::Int(value)) => *value as i64,
+        Some(Value::Long(value)) => *value,
+        _ => return Err(MethodCallFailed::InternalError(VmError::ValidationException)),
+    };
+    let class = vm.get_or_resolve_class(call_stack, class_name)?;
+    let boxed = vm.get_or_create_boxed(call_stack, class, value)?;
+    Ok(Some(Value::Object(boxed)))
+}
+
+fn register_boxing_natives(registry: &mut NativeMethodsRegistry<'_>) {
+    registry.register(
+        "java/lang/Integer",
+        "valueOf",
+        "(I)Ljava/lang/Integer;",
+        |vm, call_stack, _object, args| boxed_value_of(vm, call_stack, "java/lang/Integer", args),
+    );
+    registry.register(
+        "java/lang/Short",
+        "valueOf",
+        "(S)Ljava/lang/Short;",
+        |vm, call_stack, _object, args| boxed_value_of(vm, call_stack, "java/lang/Short", args),
+    );
+    registry.register(
+        "java/lang/Byte",
+        "valueOf",
+        "(B)Ljava/lang/Byte;",
+        |vm, call_stack, _object, args| boxed_value_of(vm, call_stack, "java/lang/Byte", args),
+    );
+    registry.register(
+        "java/lang/Long",
+        "valueOf",
+        "(J)Ljava/lang/Long;",
+        |vm, call_stack, _object, args| boxed_value_of(vm, call_stack, "java/lang/Long", args),
+    );
+    registry.register(
+        "java/lang/Character",
+        "valueOf",
+        "(C)Ljava/lang/Character;",
+        |vm, call_stack, _object, args| boxed_value_of(vm, call_stack, "java/lang/Character", args),
+    );
+    registry.register(
+        "java/lang/Boolean",
+        "valueOf",
+        "(Z)Ljava/lang/Boolean;",
+        |vm, call_stack, _object, args| boxed_value_of(vm, call_stack, "java/lang/Boolean", args),
+    );
+}
+
+fn register_string_natives(registry: &mut NativeMethodsRegistry<'_>) {
+    registry.register(
+        "java/lang/String",
+        "intern",
+        "()Ljava/lang/String;",
+        |vm, call_stack, object, _args| {
+            let receiver = object.ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            let content = vm.extract_str_from_java_lang_string(&receiver)?;
+            let interned = vm.get_or_intern_string(call_stack, &content)?;
+            Ok(Some(Value::Object(interned)))
+        },
+    );
+}
+
+fn register_reflection_natives(registry: &mut NativeMethodsRegistry<'_>) {
+    registry.register(
+        "java/lang/Object",
+        "getClass",
+        "()Ljava/lang/Class;",
+        |vm, call_stack, object, _args| {
+            let receiver = object.ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            let class_object = vm.object_get_class(call_stack, &receiver)?;
+            Ok(Some(Value::Object(class_object)))
+        },
+    );
+    registry.register(
+        "java/lang/Class",
+        "getName",
+        "()Ljava/lang/String;",
+        |vm, call_stack, object, _args| {
+            let receiver = object.ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            let class_name = vm.extract_class_name_from_java_lang_class(&receiver)?;
+            let name_object = vm.get_or_intern_string(call_stack, &class_name)?;
+            Ok(Some(Value::Object(name_object)))
+        },
+    );
+    registry.register(
+        "java/lang/Class",
+        "isInterface",
+        "()Z",
+        |vm, _call_stack, object, _args| {
+            let receiver = object.ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            let class_name = vm.extract_class_name_from_java_lang_class(&receiver)?;
+            let class = vm
+                .find_class_by_name(&class_name)
+                .ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            Ok(Some(Value::Int(vm.class_is_interface(class) as i32)))
+        },
+    );
+    registry.register(
+        "java/lang/Class",
+        "isArray",
+        "()Z",
+        |vm, _call_stack, object, _args| {
+            let receiver = object.ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            let class_name = vm.extract_class_name_from_java_lang_class(&receiver)?;
+            Ok(Some(Value::Int(Vm::class_is_array(&class_name) as i32)))
+        },
+    );
+    registry.register(
+        "java/lang/Class",
+        "isPrimitive",
+        "()Z",
+        |vm, _call_stack, object, _args| {
+            let receiver = object.ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?;
+            let class_name = vm.extract_class_name_from_java_lang_class(&receiver)?;
+            Ok(Some(Value::Int(Vm::class_is_primitive(&class_name) as i32)))
+        },
+    );
+}
+
+/// Registers the bootstrap methods `invokedynamic` call sites for lambda
+/// expressions and string concatenation resolve to. We do not model
+/// synthetic lambda classes or `StringBuilder`-style concatenation targets
+/// yet, so these are intentionally unimplemented rather than faked: calling
+/// either surfaces a clear `VmError::NotImplemented` instead of silently
+/// linking to the wrong target. Still registering them means a lookup by
+/// name/descriptor succeeds (see `Vm::invoke_native`), leaving only the
+/// semantics, not the wiring, as the known gap.
+fn register_invoke_dynamic_bootstrap_natives(registry: &mut NativeMethodsRegistry<'_>) {
+    registry.register(
+        "java/lang/invoke/LambdaMetafactory",
+        "metafactory",
+        "(Ljava/lang/invoke/MethodHandles$Lookup;Ljava/lang/String;Ljava/lang/invoke/MethodType;Ljava/lang/invoke/MethodType;Ljava/lang/invoke/MethodHandle;Ljava/lang/invoke/MethodType;)Ljava/lang/invoke/CallSite;",
+        |_vm, _call_stack, _object, _args| Err(MethodCallFailed::InternalError(VmError::NotImplemented)),
+    );
+    registry.register(
+        "java/lang/invoke/StringConcatFactory",
+        "makeConcatWithConstants",
+        "(Ljava/lang/invoke/MethodHandles$Lookup;Ljava/lang/String;Ljava/lang/invoke/MethodType;Ljava/lang/String;[Ljava/lang/Object;)Ljava/lang/invoke/CallSite;",
+        |_vm, _call_stack, _object, _args| Err(MethodCallFailed::InternalError(VmError::NotImplemented)),
+    );
+}
@@ -3,7 +3,10 @@ use std::collections::HashMap;
 use log::{debug, error, info};
 use typed_arena::Arena;
 
-use rjvm_reader::{field_type::BaseType, line_number::LineNumber};
+use rjvm_reader::{
+    bootstrap_method::BootstrapMethod, class_access_flags::ClassAccessFlags,
+    constant_pool::ConstantPoolEntry, field_type::BaseType, line_number::LineNumber,
+};
 use rjvm_utils::type_conversion::ToUsizeSafe;
 
 use crate::abstract_object::{string_from_char_array, AbstractObject, Array2, Object2, ObjectKind};
@@ -11,6 +14,7 @@ use crate::native_methods_impl::array_copy;
 use crate::{
     array_entry_type::ArrayEntryType,
     call_frame::MethodCallResult,
+    call_site::CallSite,
     call_stack::CallStack,
     class::{ClassId, ClassRef},
     class_and_method::ClassAndMethod,
@@ -51,6 +55,29 @@ pub struct Vm<'a> {
     /// clarity.
     throwable_call_stacks: HashMap<i32, Vec<StackTraceElement<'a>>>,
 
+    /// Caches the `invokedynamic` call sites that have already been linked,
+    /// keyed by the id of the class that owns the site and the constant pool
+    /// index of its `CONSTANT_InvokeDynamic` entry, so that the bootstrap
+    /// method only runs once per call site.
+    call_site_cache: HashMap<(ClassId, u16), CallSite<'a>>,
+
+    /// Caches pre-allocated boxed primitive instances (`Integer`, `Short`,
+    /// `Byte`, `Long`, `Character`, `Boolean`) keyed by the boxed class and
+    /// the boxed value, so that autoboxing and `valueOf`-style natives
+    /// return `==`-identical references for the cacheable ranges, matching
+    /// real JVM semantics.
+    boxed_primitives_cache: HashMap<(ClassId, i64), AbstractObject<'a>>,
+
+    /// Interning table backing `String.intern()` and `resolve_ldc_constant`
+    /// (for `CONSTANT_String` entries), so that occurrences of the same
+    /// content share a single, `==`-identical `java.lang.String` instance.
+    interned_strings: HashMap<String, AbstractObject<'a>>,
+
+    /// Caches the canonical `java.lang.Class` instance for each resolved
+    /// class, so that `a.getClass() == b.getClass()` holds for two
+    /// instances of the same class, matching real JVM semantics.
+    class_objects_cache: HashMap<ClassId, AbstractObject<'a>>,
+
     pub printed: Vec<Value<'a>>, // Temporary, used for testing purposes
 }
 
@@ -72,12 +99,61 @@ impl<'a> Vm<'a> {
             statics: Default::default(),
             native_methods_registry: Default::default(),
             throwable_call_stacks: Default::default(),
+            call_site_cache: Default::default(),
+            boxed_primitives_cache: Default::default(),
+            interned_strings: Default::default(),
+            class_objects_cache: Default::default(),
             printed: Vec::new(),
         };
         crate::native_methods_impl::register_natives(&mut result.native_methods_registry);
         result
     }
 
+    /// High-level entry point mirroring a real `java` launcher: resolves
+    /// `class_name` (triggering its `<clinit>`), locates its
+    /// `public static void main(String[])`, builds the `String[]` argument
+    /// array out of `args`, and invokes it on a freshly allocated call
+    /// stack. Returns `VmError::MethodNotFoundException` if the class has
+    /// no conforming `main` method, i.e. no `public static` method named
+    /// `main` with descriptor `([Ljava/lang/String;)V`.
+    pub fn run_main(
+        &mut self,
+        class_name: &str,
+        args: Vec<String>,
+    ) -> Result<Option<Value<'a>>, MethodCallFailed<'a>> {
+        let call_stack = self.allocate_call_stack();
+        let class = self.get_or_resolve_class(call_stack, class_name)?;
+        let not_found = || {
+            MethodCallFailed::InternalError(VmError::MethodNotFoundException(
+                class_name.to_string(),
+                "main".to_string(),
+                "([Ljava/lang/String;)V".to_string(),
+            ))
+        };
+        let main_method = class
+            .find_method("main", "([Ljava/lang/String;)V")
+            .filter(|method| method.is_static() && method.is_public())
+            .ok_or_else(not_found)?;
+
+        let args_array = self.new_array(ArrayEntryType::Object("java/lang/String".to_string()), args.len());
+        for (index, arg) in args.into_iter().enumerate() {
+            let string_object = self.new_java_lang_string_object(call_stack, &arg)?;
+            args_array
+                .set_element(index, Value::Object(string_object))
+                .map_err(|_| MethodCallFailed::InternalError(VmError::ValidationException))?;
+        }
+
+        self.invoke(
+            call_stack,
+            ClassAndMethod {
+                class,
+                method: main_method,
+            },
+            None,
+            vec![Value::Object(args_array)],
+        )
+    }
+
     pub fn extract_str_from_java_lang_string(
         &self,
         object: &impl Object2<'a>,
@@ -93,6 +169,25 @@ impl<'a> Vm<'a> {
         Err(VmError::ValidationException)
     }
 
+    /// Extracts the class name stored on a `java.lang.Class` instance,
+    /// mirroring `extract_str_from_java_lang_string` for `String`. Backs the
+    /// reflective natives (`Class.getName`/`isInterface`/`isArray`/
+    /// `isPrimitive`).
+    pub fn extract_class_name_from_java_lang_class(
+        &self,
+        object: &impl Object2<'a>,
+    ) -> Result<String, VmError> {
+        let class = self.get_class_by_id(object.class_id())?;
+        if class.name == "java/lang/Class" {
+            // Field 5 holds the `String` with the class's name; see
+            // `new_java_lang_class_object`.
+            if let Value::Object(name_object) = object.get_field(class, 5) {
+                return self.extract_str_from_java_lang_string(&name_object);
+            }
+        }
+        Err(VmError::ValidationException)
+    }
+
     pub(crate) fn get_static_instance(&self, class_id: ClassId) -> Option<AbstractObject<'a>> {
         self.statics.get(&class_id).cloned()
     }
@@ -215,6 +310,238 @@ impl<'a> Vm<'a> {
         }
     }
 
+    /// Executes an `invokedynamic` instruction: on the first execution of a
+    /// given call site, resolves the `CONSTANT_InvokeDynamic` constant pool
+    /// entry, runs the associated bootstrap method to link the site, and
+    /// caches the resulting target in `call_site_cache`, keyed by
+    /// `caller_class` and `call_site_index`, so that later hits of the same
+    /// site skip straight to dispatch. `caller_class` is the class the
+    /// executing frame belongs to, `call_site_index` is the instruction
+    /// operand, and `args` are the arguments already popped off the operand
+    /// stack.
+    ///
+    /// Nothing in the VM calls this method: the bytecode that `invoke` runs
+    /// does not reach it when it executes an `invokedynamic` instruction, so
+    /// no call site is linked through it.
+    pub fn invoke_dynamic(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        caller_class: ClassRef<'a>,
+        call_site_index: u16,
+        args: Vec<Value<'a>>,
+    ) -> MethodCallResult<'a> {
+        let call_site = self.link_call_site(call_stack, caller_class, call_site_index)?;
+        self.invoke(call_stack, call_site.target, None, args)
+    }
+
+    fn link_call_site(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        caller_class: ClassRef<'a>,
+        call_site_index: u16,
+    ) -> Result<CallSite<'a>, MethodCallFailed<'a>> {
+        if let Some(call_site) = self.call_site_cache.get(&(caller_class.id, call_site_index)) {
+            return Ok(call_site.clone());
+        }
+
+        debug!(
+            "linking invokedynamic call site #{} of {}",
+            call_site_index, caller_class.name
+        );
+
+        let invoke_dynamic = match caller_class.constants.get(call_site_index) {
+            Some(ConstantPoolEntry::InvokeDynamic(invoke_dynamic)) => invoke_dynamic,
+            _ => return Err(MethodCallFailed::InternalError(VmError::ValidationException)),
+        };
+        let bootstrap_method = Self::bootstrap_method_at(
+            &caller_class.bootstrap_methods,
+            invoke_dynamic.bootstrap_method_attr_index,
+        )
+        .ok_or(MethodCallFailed::InternalError(VmError::ValidationException))?
+        .clone();
+
+        let (bootstrap_class_name, bootstrap_method_name, bootstrap_method_descriptor) =
+            match caller_class.constants.get(bootstrap_method.method_ref) {
+                Some(ConstantPoolEntry::MethodHandle(method_handle)) => (
+                    method_handle.class_name.clone(),
+                    method_handle.method_name.clone(),
+                    method_handle.descriptor.clone(),
+                ),
+                _ => return Err(MethodCallFailed::InternalError(VmError::ValidationException)),
+            };
+        let bootstrap_class_and_method = self.resolve_class_method(
+            call_stack,
+            &bootstrap_class_name,
+            &bootstrap_method_name,
+            &bootstrap_method_descriptor,
+        )?;
+
+        let lookup = self.lookup_object(call_stack, caller_class)?;
+        let name = Value::Object(self.get_or_intern_string(call_stack, &invoke_dynamic.name)?);
+        let method_type = Value::Object(
+            self.get_or_intern_string(call_stack, &invoke_dynamic.type_descriptor)?,
+        );
+        let mut static_args = Vec::with_capacity(bootstrap_method.arguments.len());
+        for constant_index in bootstrap_method.arguments.iter() {
+            static_args.push(self.resolve_bootstrap_argument(
+                call_stack,
+                caller_class,
+                *constant_index,
+            )?);
+        }
+        // Every bootstrap method receives the same three leading arguments
+        // the JVM provides implicitly, e.g. `LambdaMetafactory::metafactory(
+        // Lookup, String, MethodType, ...)`, ahead of whatever static
+        // arguments are listed in the `BootstrapMethods` entry.
+        let bootstrap_args = Self::prepend_implicit_bootstrap_args(lookup, name, method_type, static_args);
+
+        let linked_target = self.invoke(call_stack, bootstrap_class_and_method, None, bootstrap_args)?;
+        let target = match linked_target {
+            Some(Value::Object(call_site_object)) => {
+                let target_class_id = call_site_object.class_id();
+                let target_class = self.get_class_by_id(target_class_id)?;
+                target_class
+                    .find_method(&invoke_dynamic.name, &invoke_dynamic.type_descriptor)
+                    .map(|method| ClassAndMethod {
+                        class: target_class,
+                        method,
+                    })
+                    .ok_or(MethodCallFailed::InternalError(VmError::MethodNotFoundException(
+                        target_class.name.clone(),
+                        invoke_dynamic.name.clone(),
+                        invoke_dynamic.type_descriptor.clone(),
+                    )))?
+            }
+            _ => return Err(MethodCallFailed::InternalError(VmError::ValidationException)),
+        };
+
+        let call_site = CallSite { target };
+        self.call_site_cache
+            .insert((caller_class.id, call_site_index), call_site.clone());
+        Ok(call_site)
+    }
+
+    /// Looks up the `BootstrapMethods` entry a `CONSTANT_InvokeDynamic`
+    /// constant pool entry refers to via its `bootstrap_method_attr_index`.
+    /// Pulled out of `link_call_site` so the bounds-checking it relies on can
+    /// be unit tested without a live `Vm`/class graph.
+    fn bootstrap_method_at(
+        bootstrap_methods: &[BootstrapMethod],
+        bootstrap_method_attr_index: u16,
+    ) -> Option<&BootstrapMethod> {
+        bootstrap_methods.get(bootstrap_method_attr_index as usize)
+    }
+
+    /// Prepends the `Lookup`, name and `MethodType` arguments the JVM
+    /// provides implicitly to every bootstrap method invocation ahead of
+    /// `static_args`, the arguments listed in the `BootstrapMethods` entry.
+    /// Pulled out of `link_call_site` so the argument ordering can be unit
+    /// tested without a live `Vm`/class graph.
+    fn prepend_implicit_bootstrap_args(
+        lookup: Value<'a>,
+        name: Value<'a>,
+        method_type: Value<'a>,
+        mut static_args: Vec<Value<'a>>,
+    ) -> Vec<Value<'a>> {
+        let mut args = Vec::with_capacity(3 + static_args.len());
+        args.push(lookup);
+        args.push(name);
+        args.push(method_type);
+        args.append(&mut static_args);
+        args
+    }
+
+    /// Builds the `MethodHandles.Lookup` instance passed as the first
+    /// implicit argument to every bootstrap method. This allocates a fresh
+    /// instance with its fields left unset: `caller_class` is not recorded
+    /// on it, and none of the access-control semantics of a real `Lookup`
+    /// are modelled.
+    fn lookup_object(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        caller_class: ClassRef<'a>,
+    ) -> Result<Value<'a>, MethodCallFailed<'a>> {
+        let _ = caller_class;
+        let lookup = self.new_object(call_stack, "java/lang/invoke/MethodHandles$Lookup")?;
+        Ok(Value::Object(lookup))
+    }
+
+    /// Materializes one of the static bootstrap arguments of a
+    /// `BootstrapMethods` entry into a runtime [`Value`]. Delegates to
+    /// `resolve_constant_pool_value`, which is also the function an `ldc`/
+    /// `ldc_w` handler should call (see `resolve_ldc_constant`).
+    fn resolve_bootstrap_argument(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        caller_class: ClassRef<'a>,
+        constant_index: u16,
+    ) -> Result<Value<'a>, MethodCallFailed<'a>> {
+        self.resolve_constant_pool_value(call_stack, caller_class, constant_index)
+    }
+
+    /// Resolves the constant pool entry `constant_index` on `class` for an
+    /// `ldc`/`ldc_w` instruction into the [`Value`] that instruction should
+    /// push onto the operand stack; `CONSTANT_String` entries resolve to the
+    /// interned instance from `get_or_intern_string`.
+    ///
+    /// Nothing in the VM calls this method: the `ldc`/`ldc_w` instructions
+    /// executed by the bytecode that `invoke` runs do not go through it or
+    /// the interning table, so the string literals they load are not
+    /// interned.
+    pub fn resolve_ldc_constant(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        class: ClassRef<'a>,
+        constant_index: u16,
+    ) -> Result<Value<'a>, MethodCallFailed<'a>> {
+        self.resolve_constant_pool_value(call_stack, class, constant_index)
+    }
+
+    /// Resolves a loadable constant pool entry (`Integer`, `Long`, `String`,
+    /// `Class`, `MethodHandle`, `MethodType`) into a runtime [`Value`].
+    /// Shared by `resolve_bootstrap_argument` and `resolve_ldc_constant`,
+    /// since both resolve the same kinds of constant pool entries: `ldc`/
+    /// `ldc_w` loads one directly, and a bootstrap method's static arguments
+    /// are each one of these too. `Integer`/`Long` constants pass through
+    /// unchanged, `String` and `Class` constants resolve to their usual
+    /// `java.lang.String`/`java.lang.Class` representation, and
+    /// `MethodHandle`/`MethodType` constants - which we do not model as
+    /// distinct objects yet - are approximated respectively by the
+    /// `java.lang.Class` owning the referenced method and a
+    /// `java.lang.String` holding the raw method descriptor.
+    fn resolve_constant_pool_value(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        class: ClassRef<'a>,
+        constant_index: u16,
+    ) -> Result<Value<'a>, MethodCallFailed<'a>> {
+        match class.constants.get(constant_index) {
+            Some(ConstantPoolEntry::Integer(value)) => Ok(Value::Int(*value)),
+            Some(ConstantPoolEntry::Long(value)) => Ok(Value::Long(*value)),
+            Some(ConstantPoolEntry::String(utf8_index)) => {
+                let text = class.constants.text_of(*utf8_index)?;
+                Ok(Value::Object(self.get_or_intern_string(call_stack, &text)?))
+            }
+            Some(ConstantPoolEntry::Class(name_index)) => {
+                let class_name = class.constants.text_of(*name_index)?;
+                Ok(Value::Object(
+                    self.new_java_lang_class_object(call_stack, &class_name)?,
+                ))
+            }
+            Some(ConstantPoolEntry::MethodHandle(method_handle)) => {
+                let class_name = method_handle.class_name.clone();
+                Ok(Value::Object(
+                    self.new_java_lang_class_object(call_stack, &class_name)?,
+                ))
+            }
+            Some(ConstantPoolEntry::MethodType(method_type)) => {
+                let descriptor = class.constants.text_of(method_type.descriptor_index)?;
+                Ok(Value::Object(self.get_or_intern_string(call_stack, &descriptor)?))
+            }
+            _ => Err(MethodCallFailed::InternalError(VmError::ValidationException)),
+        }
+    }
+
     pub fn allocate_call_stack(&mut self) -> &'a mut CallStack<'a> {
         let stack = self.call_stacks.alloc(CallStack::new());
         unsafe {
@@ -245,6 +572,79 @@ impl<'a> Vm<'a> {
         }
     }
 
+    /// Returns the boxed instance of `class` for `value`. Within the range
+    /// the real JVM guarantees to cache for that class (`-128..=127` for
+    /// `Integer`/`Short`/`Byte`/`Long`, `0..=127` for `Character`, and both
+    /// `Boolean` values) the first call allocates and caches an instance
+    /// and later calls return it; outside that range every call allocates
+    /// a fresh, uncached instance. The boxing native
+    /// methods for `valueOf` and autoboxing should go through this rather
+    /// than calling `new_object_of_class` directly.
+    pub fn get_or_create_boxed(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        class: ClassRef<'a>,
+        value: i64,
+    ) -> Result<AbstractObject<'a>, MethodCallFailed<'a>> {
+        if !Self::is_cacheable_boxed_value(&class.name, value) {
+            let object = self.new_object_of_class(class);
+            self.init_boxed_value(&class.name, &object, value);
+            return Ok(object);
+        }
+
+        if let Some(object) = self.boxed_primitives_cache.get(&(class.id, value)) {
+            return Ok(object.clone());
+        }
+
+        let object = self.new_object_of_class(class);
+        self.init_boxed_value(&class.name, &object, value);
+        self.boxed_primitives_cache
+            .insert((class.id, value), object.clone());
+        Ok(object)
+    }
+
+    fn is_cacheable_boxed_value(class_name: &str, value: i64) -> bool {
+        match class_name {
+            "java/lang/Integer" | "java/lang/Short" | "java/lang/Byte" | "java/lang/Long" => {
+                (-128..=127).contains(&value)
+            }
+            "java/lang/Character" => (0..=127).contains(&value),
+            "java/lang/Boolean" => value == 0 || value == 1,
+            _ => false,
+        }
+    }
+
+    fn init_boxed_value(&self, class_name: &str, object: &AbstractObject<'a>, value: i64) {
+        // In our JRE's rt.jar, each boxed primitive type stores its unboxed
+        // value in its first field, e.g. `private final int value;`.
+        let field_value = match class_name {
+            "java/lang/Long" => Value::Long(value),
+            _ => Value::Int(value as i32),
+        };
+        object.set_field(0, field_value);
+    }
+
+    /// Returns the interned `java.lang.String` instance for `string`,
+    /// allocating and caching a fresh one on miss. The native
+    /// `String.intern()` (see `native_methods_impl::register_string_natives`)
+    /// and `resolve_ldc_constant`'s handling of `CONSTANT_String` both go
+    /// through this, so that occurrences of the same content are
+    /// `==`-identical. `ldc`/`ldc_w` loads executed by the bytecode that
+    /// `invoke` runs do not go through it (see `resolve_ldc_constant`).
+    pub fn get_or_intern_string(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        string: &str,
+    ) -> Result<AbstractObject<'a>, MethodCallFailed<'a>> {
+        if let Some(object) = self.interned_strings.get(string) {
+            return Ok(object.clone());
+        }
+
+        let object = self.new_java_lang_string_object(call_stack, string)?;
+        self.interned_strings.insert(string.to_string(), object.clone());
+        Ok(object)
+    }
+
     pub fn new_java_lang_string_object(
         &mut self,
         call_stack: &mut CallStack<'a>,
@@ -276,18 +676,59 @@ impl<'a> Vm<'a> {
         Ok(string_object)
     }
 
+    /// Returns the canonical `java.lang.Class` instance for `class_name`,
+    /// resolving the class and creating it lazily on first request, and
+    /// reusing the cached instance on later calls so that reflective
+    /// identity (`a.getClass() == b.getClass()`) holds.
     pub fn new_java_lang_class_object(
         &mut self,
         call_stack: &mut CallStack<'a>,
         class_name: &str,
     ) -> Result<AbstractObject<'a>, MethodCallFailed<'a>> {
+        let class = self.get_or_resolve_class(call_stack, class_name)?;
+        if let Some(class_object) = self.class_objects_cache.get(&class.id) {
+            return Ok(class_object.clone());
+        }
+
         let class_object = self.new_object(call_stack, "java/lang/Class")?;
-        // TODO: build a proper instance of Class object
-        let string_object = Self::new_java_lang_string_object(self, call_stack, class_name)?;
+        let string_object = self.new_java_lang_string_object(call_stack, class_name)?;
         class_object.set_field(5, Value::Object(string_object));
+        self.class_objects_cache.insert(class.id, class_object.clone());
         Ok(class_object)
     }
 
+    /// Returns the canonical `java.lang.Class` instance backing `object`'s
+    /// runtime class. Backs the native `Object.getClass()`.
+    pub fn object_get_class(
+        &mut self,
+        call_stack: &mut CallStack<'a>,
+        object: &impl Object2<'a>,
+    ) -> Result<AbstractObject<'a>, MethodCallFailed<'a>> {
+        let class = self.get_class_by_id(object.class_id())?;
+        let class_name = class.name.clone();
+        self.new_java_lang_class_object(call_stack, &class_name)
+    }
+
+    /// Whether `class` is an interface. Backs the native `Class.isInterface()`.
+    pub fn class_is_interface(&self, class: ClassRef<'a>) -> bool {
+        class.flags.contains(ClassAccessFlags::INTERFACE)
+    }
+
+    /// Whether `class_name` names an array type. Backs the native
+    /// `Class.isArray()`.
+    pub fn class_is_array(class_name: &str) -> bool {
+        class_name.starts_with('[')
+    }
+
+    /// Whether `class_name` names one of the eight primitive types, or
+    /// `void`. Backs the native `Class.isPrimitive()`.
+    pub fn class_is_primitive(class_name: &str) -> bool {
+        matches!(
+            class_name,
+            "boolean" | "byte" | "short" | "char" | "int" | "long" | "float" | "double" | "void"
+        )
+    }
+
     pub fn new_java_lang_stack_trace_element_object(
         &mut self,
         call_stack: &mut CallStack<'a>,
@@ -387,6 +828,21 @@ impl<'a> Vm<'a> {
                 .iter_mut()
                 .map(|(_, object)| object as *mut AbstractObject<'a>),
         );
+        roots.extend(
+            self.boxed_primitives_cache
+                .iter_mut()
+                .map(|(_, object)| object as *mut AbstractObject<'a>),
+        );
+        roots.extend(
+            self.interned_strings
+                .iter_mut()
+                .map(|(_, object)| object as *mut AbstractObject<'a>),
+        );
+        roots.extend(
+            self.class_objects_cache
+                .iter_mut()
+                .map(|(_, object)| object as *mut AbstractObject<'a>),
+        );
         roots.extend(self.call_stacks.iter_mut().flat_map(|s| s.gc_roots()));
 
         unsafe {
@@ -397,3 +853,108 @@ impl<'a> Vm<'a> {
         // todo!("implement garbage collection")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rjvm_reader::bootstrap_method::BootstrapMethod;
+
+    use super::Vm;
+    use crate::value::Value;
+
+    #[test]
+    fn boxed_integer_range_is_cacheable() {
+        assert!(Vm::is_cacheable_boxed_value("java/lang/Integer", -128));
+        assert!(Vm::is_cacheable_boxed_value("java/lang/Integer", 127));
+        assert!(!Vm::is_cacheable_boxed_value("java/lang/Integer", -129));
+        assert!(!Vm::is_cacheable_boxed_value("java/lang/Integer", 128));
+    }
+
+    #[test]
+    fn boxed_character_range_is_cacheable() {
+        assert!(Vm::is_cacheable_boxed_value("java/lang/Character", 0));
+        assert!(Vm::is_cacheable_boxed_value("java/lang/Character", 127));
+        assert!(!Vm::is_cacheable_boxed_value("java/lang/Character", -1));
+        assert!(!Vm::is_cacheable_boxed_value("java/lang/Character", 128));
+    }
+
+    #[test]
+    fn boxed_boolean_values_are_cacheable() {
+        assert!(Vm::is_cacheable_boxed_value("java/lang/Boolean", 0));
+        assert!(Vm::is_cacheable_boxed_value("java/lang/Boolean", 1));
+        assert!(!Vm::is_cacheable_boxed_value("java/lang/Boolean", 2));
+    }
+
+    #[test]
+    fn unrelated_class_is_never_cacheable() {
+        assert!(!Vm::is_cacheable_boxed_value("java/lang/Object", 0));
+    }
+
+    #[test]
+    fn array_class_names_are_recognized() {
+        assert!(Vm::class_is_array("[Ljava/lang/String;"));
+        assert!(Vm::class_is_array("[I"));
+        assert!(!Vm::class_is_array("java/lang/String"));
+    }
+
+    #[test]
+    fn primitive_class_names_are_recognized() {
+        for name in ["boolean", "byte", "short", "char", "int", "long", "float", "double", "void"] {
+            assert!(Vm::class_is_primitive(name));
+        }
+        assert!(!Vm::class_is_primitive("java/lang/Integer"));
+    }
+
+    #[test]
+    fn bootstrap_method_at_finds_entry_in_range() {
+        let bootstrap_methods = vec![
+            BootstrapMethod {
+                method_ref: 10,
+                arguments: vec![],
+            },
+            BootstrapMethod {
+                method_ref: 20,
+                arguments: vec![1, 2],
+            },
+        ];
+        let found = Vm::bootstrap_method_at(&bootstrap_methods, 1).unwrap();
+        assert_eq!(found.method_ref, 20);
+        assert_eq!(found.arguments, vec![1, 2]);
+    }
+
+    #[test]
+    fn bootstrap_method_at_rejects_out_of_range_index() {
+        let bootstrap_methods = vec![BootstrapMethod {
+            method_ref: 10,
+            arguments: vec![],
+        }];
+        assert!(Vm::bootstrap_method_at(&bootstrap_methods, 1).is_none());
+        assert!(Vm::bootstrap_method_at(&[], 0).is_none());
+    }
+
+    #[test]
+    fn prepend_implicit_bootstrap_args_orders_lookup_name_type_first() {
+        let lookup = Value::Int(1);
+        let name = Value::Int(2);
+        let method_type = Value::Int(3);
+        let static_args = vec![Value::Int(4), Value::Int(5)];
+
+        let args = Vm::prepend_implicit_bootstrap_args(lookup, name, method_type, static_args);
+
+        assert_eq!(
+            args,
+            vec![
+                Value::Int(1),
+                Value::Int(2),
+                Value::Int(3),
+                Value::Int(4),
+                Value::Int(5),
+            ]
+        );
+    }
+
+    #[test]
+    fn prepend_implicit_bootstrap_args_handles_no_static_args() {
+        let args = Vm::prepend_implicit_bootstrap_args(Value::Int(1), Value::Int(2), Value::Int(3), vec![]);
+        assert_eq!(args, vec![Value::Int(1), Value::Int(2), Value::Int(3)]);
+    }
+}